@@ -1,14 +1,43 @@
 use std::collections::HashMap;
+use std::thread;
 
-use sysinfo::{Disks, System};
+use serde::Serialize;
+use sysinfo::{Components, Disks, System, MINIMUM_CPU_UPDATE_INTERVAL};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct SystemDisks {
     pub disk_type: Option<String>,
     pub file_system: Option<String>,
     pub free_space: Option<String>,
 }
-#[derive(Debug)]
+
+/// A single sensor reading pulled from sysinfo's component list, e.g. a CPU package or GPU die.
+#[derive(Debug, Serialize)]
+pub struct SystemComponent {
+    pub label: String,
+    pub temperature: Option<f32>,
+    pub max_temperature: Option<f32>,
+}
+
+/// Normalizes a `sysinfo::Component` reading into `Option<f32>` regardless of which API version
+/// is pinned: `Component::temperature()`/`max()` return a plain `f32` before sysinfo 0.33 and an
+/// `Option<f32>` from 0.33 onward. Implementing this for both return types lets the call site
+/// stay correct across that boundary without pinning to a specific minor version here.
+trait IntoOptionTemperature {
+    fn into_option_temperature(self) -> Option<f32>;
+}
+impl IntoOptionTemperature for f32 {
+    fn into_option_temperature(self) -> Option<f32> {
+        Some(self)
+    }
+}
+impl IntoOptionTemperature for Option<f32> {
+    fn into_option_temperature(self) -> Option<f32> {
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
 pub struct SystemInfo {
     pub system_name: String,
     pub kernel_version: String,
@@ -16,17 +45,35 @@ pub struct SystemInfo {
     pub hostname: String,
     pub cpu_cores: usize,
     pub cpu_virtual_cores: usize,
+    pub cpu_usage_per_core: Vec<f32>,
+    pub cpu_usage_average: f32,
     pub total_memory: u64,
     pub used_memory: u64,
     pub total_swap: u64,
     pub used_swap: u64,
     pub disks: Vec<SystemDisks>,
+    pub components: Vec<SystemComponent>,
 }
 impl SystemInfo {
     pub fn new() -> Self {
         let mut sys = System::new_all();
         sys.refresh_all();
+
+        // A single CPU usage sample is meaningless; sysinfo needs two samples spaced at least
+        // `MINIMUM_CPU_UPDATE_INTERVAL` apart to compute a usage delta per core.
+        sys.refresh_cpu_usage();
+        thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+        sys.refresh_cpu_usage();
+
+        let cpu_usage_per_core: Vec<f32> = sys.cpus().iter().map(|cpu| cpu.cpu_usage()).collect();
+        let cpu_usage_average = if cpu_usage_per_core.is_empty() {
+            0.0
+        } else {
+            cpu_usage_per_core.iter().sum::<f32>() / cpu_usage_per_core.len() as f32
+        };
+
         let disks = Disks::new_with_refreshed_list();
+        let components = Components::new_with_refreshed_list();
 
         Self {
             system_name: System::name().unwrap_or_default(),
@@ -35,6 +82,8 @@ impl SystemInfo {
             hostname: System::host_name().unwrap_or_default(),
             cpu_cores: num_cpus::get_physical(),
             cpu_virtual_cores: num_cpus::get(),
+            cpu_usage_per_core,
+            cpu_usage_average,
             total_memory: sys.total_memory(),
             used_memory: sys.used_memory(),
             total_swap: sys.total_swap(),
@@ -47,6 +96,14 @@ impl SystemInfo {
                     free_space: Some(d.available_space().to_string()),
                 })
                 .collect(),
+            components: components
+                .iter()
+                .map(|c| SystemComponent {
+                    label: c.label().to_string(),
+                    temperature: c.temperature().into_option_temperature(),
+                    max_temperature: c.max().into_option_temperature(),
+                })
+                .collect(),
         }
     }
 pub fn new_to_hashmap(sys_info: Self) -> HashMap<String, String> {
@@ -64,22 +121,52 @@ pub fn new_to_hashmap(sys_info: Self) -> HashMap<String, String> {
     infomap.insert("Used Memory".to_string(), sys_info.used_memory.to_string());
     infomap.insert("Total Swap".to_string(), sys_info.total_swap.to_string());
     infomap.insert("Used Swap".to_string(), sys_info.used_swap.to_string());
+    infomap.insert(
+        "CPU Usage Average".to_string(),
+        sys_info.cpu_usage_average.to_string(),
+    );
+    for (i, usage) in sys_info.cpu_usage_per_core.iter().enumerate() {
+        infomap.insert(format!("CPU {} Usage", i), usage.to_string());
+    }
+
+    for component in &sys_info.components {
+        if let Some(temperature) = component.temperature {
+            infomap.insert(format!("{} Temperature", component.label), temperature.to_string());
+        }
+    }
 
-    for disk in &sys_info.disks {
+    for (i, disk) in sys_info.disks.iter().enumerate() {
         if let Some(disk_type) = &disk.disk_type {
-            infomap.insert("Disk Type".to_string(), disk_type.clone());
+            infomap.insert(format!("Disk {} Type", i), disk_type.clone());
         }
         if let Some(file_system) = &disk.file_system {
-            infomap.insert("File System".to_string(), file_system.clone());
+            infomap.insert(format!("Disk {} File System", i), file_system.clone());
         }
         if let Some(free_space) = &disk.free_space {
-            infomap.insert("Free Space".to_string(), free_space.clone());
+            infomap.insert(format!("Disk {} Free Space", i), free_space.clone());
         }
     }
 
     infomap
 }
 
+    /// Serializes the system info to a single-line JSON string.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Serializes the system info to an indented, human-readable JSON string.
+    pub fn to_pretty_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Prints the system info as pretty-printed JSON, for machine-readable consumption by
+    /// dashboards and log pipelines instead of the `display` plain-text format.
+    pub fn display_json(&self) -> serde_json::Result<()> {
+        println!("{}", self.to_pretty_json()?);
+        Ok(())
+    }
+
     pub fn display(&self) {
         println!("System Name: {}", self.system_name);
         println!("Kernel Version: {}", self.kernel_version);
@@ -87,11 +174,14 @@ pub fn new_to_hashmap(sys_info: Self) -> HashMap<String, String> {
         println!("Hostname: {}", self.hostname);
         println!("CPU Cores: {}", self.cpu_cores);
         println!("CPU Virtual Cores: {}", self.cpu_virtual_cores);
+        println!("CPU Usage Per Core: {:?}", self.cpu_usage_per_core);
+        println!("CPU Usage Average: {:.1}%", self.cpu_usage_average);
         println!("Total Memory: {}", self.total_memory);
         println!("Used Memory: {}", self.used_memory);
         println!("Total Swap: {}", self.total_swap);
         println!("Used Swap: {}", self.used_swap);
         println!("Disks: {:?}", self.disks);
+        println!("Components: {:?}", self.components);
         // for disk in &self.disks {
         //     println!(
         //         "Disk Type: {:?}, File System: {:?}, Free Space: {:?}",
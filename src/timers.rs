@@ -7,16 +7,25 @@
  *
  * ## Features
  * - **Timer Struct**: Represents a single timer with start and end times, and provides methods to calculate the duration.
+ * - **CpuTimer Struct**: Measures consumed process CPU time instead of wall-clock time, and can be paused and
+ *   resumed across multiple start/stop cycles while only accumulating time spent in the measured region.
  * - **Timers Struct**: A collection of named timers stored in a `HashMap`, allowing multiple timers to be managed simultaneously.
+ * - **TimerHeap Struct**: A deadline-scheduling min-heap keyed by absolute expiry, for polling loops that need
+ *   to know which of several named events have fired.
  * - **Macros**:
  *   - **TC!**: Creates a new `Timers` instance with a specified timer name.
  *   - **TE!**: Ends a timer and calculates its duration.
  *   - **TA!**: Adds a new timer to an existing `Timers` instance.
  *   - **TR!**: Calculates the rate (e.g., operations per second) for a timer.
  *   - **TD!**: Retrieves the duration of a timer.
+ *   - **TCC!**: Creates a new `Timers` instance with a specified CPU timer name.
+ *   - **TEC!**: Stops a CPU timer and returns its accumulated duration in nanoseconds.
+ *   - **TRC!**: Calculates the rate (e.g., operations per second) for a CPU timer.
+ *   - **TDC!**: Retrieves the accumulated duration of a CPU timer in nanoseconds.
  *
  * ## Dependencies
  * - `chrono`: Used for date and time operations, such as tracking start and end times.
+ * - `cpu_time`: Used to sample consumed process CPU time for `CpuTimer`.
  * - `std::collections::HashMap`: Used to store and manage multiple timers.
  *
  * ## Usage
@@ -31,6 +40,14 @@
  *   - `end()`: Updates the end time to the current time.
  *   - `duration()`: Calculates the duration in milliseconds between the start and end times.
  *
+ * ### CpuTimer
+ * - Tracks consumed process CPU time rather than wall-clock time.
+ * - Provides methods:
+ *   - `new()`: Creates a new CPU timer, running from the current process CPU time.
+ *   - `start()`: Records a fresh `ProcessTime` snapshot and resumes accumulation.
+ *   - `stop()`: Folds the time elapsed since the last `start()` into `total_ns` and marks the timer inactive.
+ *   - `duration_ns()`: Returns `total_ns`, plus the elapsed-since-last-start if the timer is currently running.
+ *
  * ### Timers
  * - Manages multiple timers using a `HashMap`.
  * - Provides methods:
@@ -38,6 +55,22 @@
  *   - `rate(timer_name: String, qty: i64)`: Calculates the rate (e.g., operations per second) for a timer.
  *   - `duration(timer_name: String)`: Retrieves the duration of a timer.
  *   - `end(timer_name: &String)`: Ends a timer and returns its duration.
+ *   - `new_cpu(timer_name: String)`: Creates a new `Timers` instance with a single CPU timer.
+ *   - `add_cpu(timer_name: String)`: Adds a new CPU timer to an existing `Timers` instance.
+ *   - `start_cpu(timer_name: &String)`: Resumes a paused CPU timer.
+ *   - `end_cpu(timer_name: &String)`: Pauses a CPU timer and returns `Some` accumulated duration in nanoseconds, or `None` if unregistered.
+ *   - `duration_cpu(timer_name: String)`: Retrieves `Some` accumulated duration of a CPU timer in nanoseconds, or `None` if unregistered.
+ *   - `rate_cpu(timer_name: String, qty: i64)`: Calculates `Some` rate of operations per CPU-second for a CPU timer, or `None` if unregistered.
+ *
+ * ### TimerHeap
+ * - Schedules named entries keyed by an absolute expiry, backed by a `BinaryHeap`.
+ * - Provides methods:
+ *   - `insert(key: T, duration_ms: i64)`: Schedules a one-shot entry to expire `duration_ms` from now.
+ *   - `insert_periodic(key: T, duration_ms: i64)`: Schedules a recurring entry that re-arms itself when it fires.
+ *   - `upsert(key: T, duration_ms: i64)`: Replaces any existing entry with the same key instead of duplicating it.
+ *   - `remove(key: &T)`: Cancels the entry with the given key, if any.
+ *   - `time_remaining()`: Returns the milliseconds until the soonest entry, or `None` if the heap is empty.
+ *   - `expired()`: Returns an iterator that lazily pops and yields every entry due by now, re-arming periodic ones.
  *
  * ## Macros
  * - **TC!**: Shorthand for creating a `Timers` instance.
@@ -45,11 +78,17 @@
  * - **TA!**: Shorthand for adding a new timer to an existing `Timers` instance.
  * - **TR!**: Shorthand for calculating the rate of operations for a timer.
  * - **TD!**: Shorthand for retrieving the duration of a timer.
+ * - **TCC!**: Shorthand for creating a `Timers` instance with a CPU timer.
+ * - **TEC!**: Shorthand for stopping a CPU timer and retrieving its accumulated duration.
+ * - **TRC!**: Shorthand for calculating the rate of operations for a CPU timer.
+ * - **TDC!**: Shorthand for retrieving the accumulated duration of a CPU timer.
  *
  */
 
-use chrono::{DateTime, Local};
-use std::collections::HashMap;
+use chrono::{DateTime, Duration, Local};
+use cpu_time::ProcessTime;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
 
 #[derive(Clone, Copy)]
 pub struct Timer {
@@ -84,16 +123,117 @@ impl Default for Timer {
     }
 }
 
+/// A timer that measures consumed process CPU time rather than wall-clock time.
+///
+/// Unlike `Timer`, a `CpuTimer` can be paused and resumed across multiple start/stop cycles:
+/// only the time spent in the measured region while running is added to `total_ns`, so a timer
+/// can be stopped while the process is blocked on I/O and resumed afterwards without skewing
+/// the result.
+#[derive(Clone, Copy)]
+pub struct CpuTimer {
+    pub snapshot: ProcessTime,
+    pub total_ns: u128,
+    pub running: bool,
+}
+impl CpuTimer {
+    pub fn new() -> Self {
+        Self {
+            snapshot: ProcessTime::now(),
+            total_ns: 0,
+            running: true,
+        }
+    }
+
+    pub fn start(&mut self) {
+        self.snapshot = ProcessTime::now();
+        self.running = true;
+    }
+
+    pub fn stop(&mut self) {
+        if self.running {
+            self.total_ns += ProcessTime::now().duration_since(self.snapshot).as_nanos();
+            self.running = false;
+        }
+    }
+
+    pub fn duration_ns(&self) -> u128 {
+        if self.running {
+            self.total_ns + ProcessTime::now().duration_since(self.snapshot).as_nanos()
+        } else {
+            self.total_ns
+        }
+    }
+}
+
+impl Default for CpuTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive()]
 pub struct Timers {
     pub timer: HashMap<String, Timer>,
+    pub cpu_timer: HashMap<String, CpuTimer>,
 }
 impl Timers {
     pub fn new(timer_name: String) -> Self {
         let new_timer: Timer = Timer::new();
         let mut _map: HashMap<String, Timer> = HashMap::new();
         _map.insert(timer_name, new_timer);
-        Self { timer: _map }
+        Self {
+            timer: _map,
+            cpu_timer: HashMap::new(),
+        }
+    }
+
+    pub fn new_cpu(timer_name: String) -> Self {
+        let new_timer: CpuTimer = CpuTimer::new();
+        let mut _map: HashMap<String, CpuTimer> = HashMap::new();
+        _map.insert(timer_name, new_timer);
+        Self {
+            timer: HashMap::new(),
+            cpu_timer: _map,
+        }
+    }
+
+    pub fn add_cpu(&mut self, timer_name: String) {
+        self.cpu_timer.insert(timer_name, CpuTimer::new());
+    }
+
+    pub fn start_cpu(&mut self, timer_name: &String) {
+        if let Some(_timer) = self.cpu_timer.get_mut(timer_name) {
+            _timer.start();
+        }
+    }
+
+    /// Stops the named CPU timer and returns its accumulated duration in nanoseconds, or `None`
+    /// if no CPU timer with that name has been registered.
+    pub fn end_cpu(&mut self, timer_name: &String) -> Option<u128> {
+        let _timer = self.cpu_timer.get_mut(timer_name);
+        _timer.map(|_timer| {
+            _timer.stop();
+            _timer.duration_ns()
+        })
+    }
+
+    /// Returns the accumulated duration of the named CPU timer in nanoseconds, or `None` if no
+    /// CPU timer with that name has been registered.
+    pub fn duration_cpu(&mut self, timer_name: String) -> Option<u128> {
+        self.cpu_timer.get(&timer_name).map(|_timer| _timer.duration_ns())
+    }
+
+    /// Returns `Some` rate of operations per CPU-second for the named timer, or `None` if no CPU
+    /// timer with that name has been registered - consistent with `end_cpu`/`duration_cpu`.
+    pub fn rate_cpu(&mut self, timer_name: String, qty: i64) -> Option<i64> {
+        self.cpu_timer.get(&timer_name).map(|_timer| {
+            let _duration_ns: u128 = if _timer.duration_ns() == 0 {
+                1
+            } else {
+                _timer.duration_ns()
+            };
+            (qty as i128 * 1_000_000_000 / _duration_ns as i128) as i64
+        })
     }
 
     pub fn rate(&mut self, timer_name: String, qty: i64) -> i64 {
@@ -135,6 +275,7 @@ impl Default for Timers {
     fn default() -> Self {
         Self {
             timer: HashMap::new(),
+            cpu_timer: HashMap::new(),
         }
     }
 }
@@ -170,3 +311,147 @@ macro_rules! TD {
         $timerobject.duration($timer.to_string())
     };
 }
+
+// Shorthand for creating a CPU Timer.
+#[macro_export]
+macro_rules! TCC {
+    ($e:expr) => { wolves_cli_helper::timers::Timers::new_cpu(String::from($e)) };
+}
+
+// Shorthand for stopping a CPU Timer.
+#[macro_export]
+macro_rules! TEC {
+    ($name:expr, $var:expr) => {
+        $var.end_cpu(&String::from($name))
+    }
+}
+
+#[macro_export]
+macro_rules! TRC {
+    ($timer:expr, $timerobject:expr, $count:expr) => {
+        $timerobject.rate_cpu($timer.to_string(), $count)
+    };
+}
+#[macro_export]
+macro_rules! TDC {
+    ($timer:expr, $timerobject:expr) => {
+        $timerobject.duration_cpu($timer.to_string())
+    };
+}
+
+/// A single scheduled entry in a `TimerHeap`, ordered by expiry only.
+///
+/// The `Ord` impl is reversed so that a `BinaryHeap<HeapEntry<T>>`, which is normally a max-heap,
+/// surfaces the *soonest* expiry at its peek - i.e. behaves as a min-heap.
+struct HeapEntry<T> {
+    expiry: DateTime<Local>,
+    key: T,
+    recurring_ms: Option<i64>,
+}
+
+impl<T> PartialEq for HeapEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.expiry == other.expiry
+    }
+}
+impl<T> Eq for HeapEntry<T> {}
+impl<T> PartialOrd for HeapEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<T> Ord for HeapEntry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.expiry.cmp(&self.expiry)
+    }
+}
+
+/// A deadline-scheduling heap of named entries, for polling loops that need to know which of
+/// several scheduled callbacks/events are due.
+///
+/// Entries are stored in a binary min-heap keyed by absolute expiry (`DateTime<Local>`), so the
+/// soonest deadline is always at the top regardless of insertion order.
+pub struct TimerHeap<T> {
+    heap: BinaryHeap<HeapEntry<T>>,
+}
+
+impl<T: Clone + PartialEq> TimerHeap<T> {
+    pub fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    fn insert_entry(&mut self, key: T, duration_ms: i64, recurring_ms: Option<i64>) {
+        let expiry = Local::now() + Duration::milliseconds(duration_ms);
+        self.heap.push(HeapEntry {
+            expiry,
+            key,
+            recurring_ms,
+        });
+    }
+
+    /// Schedules a one-shot entry to expire `duration_ms` milliseconds from now.
+    pub fn insert(&mut self, key: T, duration_ms: i64) {
+        self.insert_entry(key, duration_ms, None);
+    }
+
+    /// Schedules a recurring entry that, once it expires, is re-armed with the same interval.
+    pub fn insert_periodic(&mut self, key: T, duration_ms: i64) {
+        self.insert_entry(key, duration_ms, Some(duration_ms));
+    }
+
+    /// Replaces any existing entry with the same key rather than duplicating it.
+    pub fn upsert(&mut self, key: T, duration_ms: i64) {
+        self.remove(&key);
+        self.insert(key, duration_ms);
+    }
+
+    /// Cancels the entry with the given key, if any is scheduled.
+    pub fn remove(&mut self, key: &T) {
+        self.heap = self.heap.drain().filter(|entry| entry.key != *key).collect();
+    }
+
+    /// Returns the milliseconds until the soonest entry expires, or `None` if the heap is empty.
+    pub fn time_remaining(&self) -> Option<i64> {
+        self.heap.peek().map(|entry| {
+            (entry.expiry - Local::now())
+                .num_milliseconds()
+                .max(0)
+        })
+    }
+
+    /// Returns an iterator that lazily pops and yields every entry whose expiry has passed,
+    /// re-inserting recurring entries with a fresh deadline as they fire.
+    pub fn expired(&mut self) -> Expired<'_, T> {
+        Expired { heap: self }
+    }
+}
+
+impl<T: Clone + PartialEq> Default for TimerHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator returned by `TimerHeap::expired`. Pops due entries one at a time without allocating,
+/// re-arming any entry that was registered with `insert_periodic`.
+pub struct Expired<'a, T> {
+    heap: &'a mut TimerHeap<T>,
+}
+
+impl<T: Clone + PartialEq> Iterator for Expired<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let is_due = matches!(self.heap.heap.peek(), Some(entry) if entry.expiry <= Local::now());
+        if !is_due {
+            return None;
+        }
+        let entry = self.heap.heap.pop()?;
+        if let Some(duration_ms) = entry.recurring_ms {
+            self.heap.insert_periodic(entry.key.clone(), duration_ms);
+        }
+        Some(entry.key)
+    }
+}
@@ -36,4 +36,76 @@ pub fn paddingline() -> Result<(), std::io::Error> {
 #[macro_export]
 macro_rules! say {
     ($e:expr) => { wolves_cli_helper::verbose::say($e).unwrap() };
+}
+
+const SPARKLINE_BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Renders a series of samples as a compact Unicode bar graph, one block character per sample.
+///
+/// Each sample is normalized against the min/max of the window and mapped onto one of the eight
+/// block characters. If every sample is equal (a zero range), the lowest block is emitted for all
+/// of them rather than dividing by zero.
+pub fn sparkline(samples: &[f64]) -> String {
+    let min = samples.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = samples.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+
+    let glyphs: String = samples
+        .iter()
+        .map(|&sample| {
+            if range <= f64::EPSILON {
+                SPARKLINE_BLOCKS[0]
+            } else {
+                let bucket = ((sample - min) / range * (SPARKLINE_BLOCKS.len() - 1) as f64).round();
+                SPARKLINE_BLOCKS[bucket as usize]
+            }
+        })
+        .collect();
+
+    style(glyphs).cyan().to_string()
+}
+
+/// A fixed-capacity ring buffer of samples, for feeding `sparkline` a rolling metric history.
+pub struct Window {
+    capacity: usize,
+    samples: Vec<f64>,
+    next: usize,
+}
+
+impl Window {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "Window capacity must be greater than zero");
+        Self {
+            capacity,
+            samples: Vec::with_capacity(capacity),
+            next: 0,
+        }
+    }
+
+    /// Pushes a new sample, overwriting the oldest one once the window is full.
+    pub fn push(&mut self, value: f64) {
+        if self.samples.len() < self.capacity {
+            self.samples.push(value);
+        } else {
+            self.samples[self.next] = value;
+            self.next = (self.next + 1) % self.capacity;
+        }
+    }
+
+    /// Returns the samples currently held, oldest first.
+    pub fn samples(&self) -> Vec<f64> {
+        if self.samples.len() < self.capacity {
+            return self.samples.clone();
+        }
+        let mut ordered = Vec::with_capacity(self.capacity);
+        ordered.extend_from_slice(&self.samples[self.next..]);
+        ordered.extend_from_slice(&self.samples[..self.next]);
+        ordered
+    }
+}
+
+// Shorthand for rendering a sparkline of a sample series to the terminal.
+#[macro_export]
+macro_rules! say_sparkline {
+    ($samples:expr) => { wolves_cli_helper::verbose::say(&wolves_cli_helper::verbose::sparkline($samples)).unwrap() };
 }
\ No newline at end of file
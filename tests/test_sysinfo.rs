@@ -18,10 +18,20 @@ mod tests {
         assert!(sys_info.total_swap >= sys_info.used_swap);
     }
 
+    #[test]
+    fn test_cpu_usage_sampling() {
+        let sys_info = SystemInfo::new();
+
+        // One usage reading per logical core, and an average within a sane percentage range.
+        assert_eq!(sys_info.cpu_usage_per_core.len(), sys_info.cpu_virtual_cores);
+        assert!(sys_info.cpu_usage_average >= 0.0);
+        assert!(sys_info.cpu_usage_average <= 100.0);
+    }
+
     #[test]
     fn test_to_hashmap() {
         let sys_info = SystemInfo::new();
-        let hashmap = sys_info.to_hashmap();
+        let hashmap = SystemInfo::new_to_hashmap(sys_info);
 
         // Check that the hashmap contains expected keys
         assert!(hashmap.contains_key("System Name"));
@@ -35,6 +45,28 @@ mod tests {
         assert!(hashmap.contains_key("Total Swap"));
         assert!(hashmap.contains_key("Used Swap"));
     }
+
+    #[test]
+    fn test_to_hashmap_keeps_every_disk() {
+        let sys_info = SystemInfo::new();
+        let disk_count = sys_info.disks.len();
+        let hashmap = SystemInfo::new_to_hashmap(sys_info);
+
+        // Every disk gets its own indexed keys, so none are overwritten on multi-disk machines.
+        for i in 0..disk_count {
+            assert!(hashmap.contains_key(&format!("Disk {} Free Space", i)));
+        }
+    }
+
+    #[test]
+    fn test_to_json_round_trips() {
+        let sys_info = SystemInfo::new();
+        let json = sys_info.to_json().expect("serialization should succeed");
+
+        let parsed: serde_json::Value =
+            serde_json::from_str(&json).expect("output should be valid JSON");
+        assert!(parsed.get("system_name").is_some());
+    }
 }
 /* 
     #[test]
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+
+    use std::thread;
+    use std::time::Duration as StdDuration;
+
+    use wolves_cli_helper::timers::{CpuTimer, TimerHeap, Timers};
+
+    #[test]
+    fn test_cpu_timer_pause_resume_accumulates() {
+        let mut timer = CpuTimer::new();
+        thread::sleep(StdDuration::from_millis(5));
+        timer.stop();
+        let after_first_stop = timer.duration_ns();
+        assert!(after_first_stop > 0);
+
+        // A second start/stop cycle should add to the total, not reset it.
+        timer.start();
+        thread::sleep(StdDuration::from_millis(5));
+        timer.stop();
+        assert!(timer.duration_ns() > after_first_stop);
+    }
+
+    #[test]
+    fn test_cpu_timer_duration_ns_includes_in_flight_interval() {
+        let mut timer = CpuTimer::new();
+        thread::sleep(StdDuration::from_millis(5));
+        timer.stop();
+        let paused = timer.duration_ns();
+
+        timer.start();
+        thread::sleep(StdDuration::from_millis(5));
+        // Still running: duration_ns() should fold in the elapsed-since-start on top of total_ns.
+        assert!(timer.duration_ns() > paused);
+    }
+
+    #[test]
+    fn test_timers_end_cpu_roundtrip() {
+        let mut timers = Timers::new_cpu("work".to_string());
+        thread::sleep(StdDuration::from_millis(5));
+
+        let duration = timers.end_cpu(&"work".to_string());
+        assert!(duration.unwrap() > 0);
+
+        // An unregistered timer name is None, not a 0 sentinel.
+        assert!(timers.end_cpu(&"missing".to_string()).is_none());
+    }
+
+    #[test]
+    fn test_timer_heap_orders_soonest_expiry_first() {
+        let mut heap = TimerHeap::new();
+        heap.insert("slow", 60);
+        heap.insert("fast", 5);
+        heap.insert("medium", 25);
+
+        thread::sleep(StdDuration::from_millis(70));
+        let order: Vec<_> = heap.expired().collect();
+        assert_eq!(order, vec!["fast", "medium", "slow"]);
+    }
+
+    #[test]
+    fn test_timer_heap_upsert_replaces_rather_than_duplicates() {
+        let mut heap = TimerHeap::new();
+        heap.insert("task", 1000);
+        heap.upsert("task", 5);
+
+        thread::sleep(StdDuration::from_millis(20));
+        let expired: Vec<_> = heap.expired().collect();
+        assert_eq!(expired, vec!["task"]);
+    }
+
+    #[test]
+    fn test_timer_heap_remove_present_and_absent_key() {
+        let mut heap = TimerHeap::new();
+        heap.insert("task", 5);
+        heap.remove(&"task");
+
+        thread::sleep(StdDuration::from_millis(20));
+        assert_eq!(heap.expired().count(), 0);
+
+        // Removing a key that was never scheduled is a no-op, not an error.
+        heap.remove(&"missing");
+    }
+
+    #[test]
+    fn test_timer_heap_time_remaining_clamps_to_zero_for_past_deadline() {
+        let mut heap = TimerHeap::new();
+        heap.insert("task", -1000);
+        assert_eq!(heap.time_remaining(), Some(0));
+    }
+
+    #[test]
+    fn test_timer_heap_expired_rearms_periodic_entry() {
+        let mut heap = TimerHeap::new();
+        heap.insert_periodic("tick", 5);
+
+        thread::sleep(StdDuration::from_millis(20));
+        let first: Vec<_> = heap.expired().collect();
+        assert_eq!(first, vec!["tick"]);
+
+        // The entry should be re-armed with a fresh deadline rather than dropped.
+        assert!(heap.time_remaining().is_some());
+        thread::sleep(StdDuration::from_millis(20));
+        let second: Vec<_> = heap.expired().collect();
+        assert_eq!(second, vec!["tick"]);
+    }
+}
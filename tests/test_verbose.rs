@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+
+    use wolves_cli_helper::verbose::{sparkline, Window};
+
+    #[test]
+    fn test_sparkline_zero_range_uses_lowest_block() {
+        let samples = [3.0, 3.0, 3.0];
+        let line = sparkline(&samples);
+
+        // A flat series has no range to normalize against, so every sample falls in the lowest bucket.
+        assert_eq!(line.chars().filter(|&c| c == '\u{2581}').count(), 3);
+        assert!(!line.contains('\u{2588}'));
+    }
+
+    #[test]
+    fn test_sparkline_min_and_max_hit_bucket_boundaries() {
+        let samples = [0.0, 10.0];
+        let line = sparkline(&samples);
+
+        assert!(line.contains('\u{2581}')); // lowest block for the minimum sample
+        assert!(line.contains('\u{2588}')); // highest block for the maximum sample
+    }
+
+    #[test]
+    fn test_window_push_past_capacity_returns_oldest_first() {
+        let mut window = Window::new(3);
+        window.push(1.0);
+        window.push(2.0);
+        window.push(3.0);
+        window.push(4.0);
+
+        assert_eq!(window.samples(), vec![2.0, 3.0, 4.0]);
+    }
+}